@@ -11,7 +11,7 @@ fn main() {
 
     // Add some dummy audio samples (silence)
     let samples = vec![0.0f32; 44100 * 10]; // 10 seconds of silence
-    audio.add_samples(&samples);
+    audio.push_samples(&samples);
 
     // Detect the key
     let key = kf.key_of_audio(&audio);
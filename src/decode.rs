@@ -0,0 +1,186 @@
+//! Optional audio file decoding via [Symphonia](https://docs.rs/symphonia/), enabled
+//! with the `symphonia` feature.
+//!
+//! This removes the need to hand-roll PCM decoding: [`AudioData::from_path`] and
+//! [`AudioData::from_reader`] probe the container, decode whatever codec Symphonia
+//! supports (MP3, WAV, FLAC, OGG, ...), and feed the normalized samples straight
+//! into the bulk ingestion path in [`AudioData::push_samples`].
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::{MediaSource, MediaSourceStream, MediaSourceStreamOptions};
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::AudioData;
+
+/// Adapts an arbitrary `Read + Seek` source into Symphonia's [`MediaSource`],
+/// truthfully reporting seekability instead of the always-`false` answer
+/// [`symphonia::core::io::ReadOnlySource`] would give. Demuxers (e.g. the MP3
+/// one) gate seek-based behavior on `is_seekable()`, so misreporting it here
+/// would silently degrade decoding of genuinely seekable sources.
+struct SeekableSource<R> {
+    inner: R,
+    byte_len: Option<u64>,
+}
+
+impl<R: Read + Seek + Send + Sync> SeekableSource<R> {
+    /// Wraps `inner`, probing its length up front (`byte_len` needs `&self`, so
+    /// the length can't be measured lazily via a seek-to-end/seek-back without
+    /// interior mutability).
+    fn new(mut inner: R) -> Self {
+        let byte_len = inner.stream_position().ok().and_then(|current| {
+            let len = inner.seek(SeekFrom::End(0)).ok()?;
+            inner.seek(SeekFrom::Start(current)).ok()?;
+            Some(len)
+        });
+
+        SeekableSource { inner, byte_len }
+    }
+}
+
+impl<R: Read + Seek + Send + Sync> Read for SeekableSource<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<R: Read + Seek + Send + Sync> Seek for SeekableSource<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+impl<R: Read + Seek + Send + Sync> MediaSource for SeekableSource<R> {
+    fn is_seekable(&self) -> bool {
+        true
+    }
+
+    fn byte_len(&self) -> Option<u64> {
+        self.byte_len
+    }
+}
+
+/// Errors that can occur while decoding an audio file into [`AudioData`].
+#[derive(Debug)]
+pub enum DecodeError {
+    /// Reading the underlying file or stream failed.
+    Io(std::io::Error),
+    /// Symphonia could not probe, demux, or decode the stream.
+    Decode(SymphoniaError),
+    /// The probed container had no decodable audio track.
+    NoAudioTrack,
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::Io(err) => write!(f, "failed to read audio stream: {err}"),
+            DecodeError::Decode(err) => write!(f, "failed to decode audio stream: {err}"),
+            DecodeError::NoAudioTrack => write!(f, "stream has no decodable audio track"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DecodeError::Io(err) => Some(err),
+            DecodeError::Decode(err) => Some(err),
+            DecodeError::NoAudioTrack => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DecodeError {
+    fn from(err: std::io::Error) -> Self {
+        DecodeError::Io(err)
+    }
+}
+
+impl From<SymphoniaError> for DecodeError {
+    fn from(err: SymphoniaError) -> Self {
+        DecodeError::Decode(err)
+    }
+}
+
+impl AudioData {
+    /// Decode an audio file at `path` directly into an [`AudioData`].
+    ///
+    /// The frame rate and channel count are taken from the probed format, so
+    /// `reduce_to_mono`/`downsample` behave the same as with manually decoded PCM.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<AudioData, DecodeError> {
+        AudioData::from_reader(File::open(path)?)
+    }
+
+    /// Decode audio from an arbitrary seekable reader into an [`AudioData`].
+    ///
+    /// Interleaving and channel count from the source are preserved.
+    pub fn from_reader<R: Read + Seek + Send + Sync + 'static>(
+        reader: R,
+    ) -> Result<AudioData, DecodeError> {
+        let stream = MediaSourceStream::new(
+            Box::new(SeekableSource::new(reader)),
+            MediaSourceStreamOptions::default(),
+        );
+
+        let probed = symphonia::default::get_probe().format(
+            &Hint::new(),
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )?;
+
+        let mut format = probed.format;
+        let track_id = format
+            .tracks()
+            .iter()
+            .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+            .map(|track| track.id)
+            .ok_or(DecodeError::NoAudioTrack)?;
+
+        let track = format.tracks().iter().find(|t| t.id == track_id).unwrap();
+        let mut decoder = symphonia::default::get_codecs()
+            .make(&track.codec_params, &DecoderOptions::default())?;
+
+        let mut audio = AudioData::new();
+        let mut sample_buf: Option<SampleBuffer<f32>> = None;
+
+        loop {
+            let packet = match format.next_packet() {
+                Ok(packet) => packet,
+                Err(SymphoniaError::IoError(err))
+                    if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+                {
+                    break
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            if packet.track_id() != track_id {
+                continue;
+            }
+
+            let decoded = decoder.decode(&packet)?;
+
+            if sample_buf.is_none() {
+                let spec = *decoded.spec();
+                audio.set_frame_rate(spec.rate);
+                audio.set_channels(spec.channels.count() as u32);
+                sample_buf = Some(SampleBuffer::new(decoded.capacity() as u64, spec));
+            }
+
+            let buf = sample_buf.as_mut().unwrap();
+            buf.copy_interleaved_ref(decoded);
+            audio.push_samples(buf.samples());
+        }
+
+        Ok(audio)
+    }
+}
@@ -64,12 +64,55 @@
 //! // Convert to mono for more efficient processing
 //! audio.reduce_to_mono();
 //!
-//! // Downsample by a factor of 2
+//! // Low-pass filter before downsampling to avoid aliasing (requires mono audio)
+//! audio.low_pass_filter(2).expect("audio is mono");
+//!
+//! // Downsample by a factor of 2 (must match the factor passed above)
 //! audio.downsample(2);
 //!
 //! assert_eq!(audio.channels(), 1);
 //! assert_eq!(audio.frame_rate(), 22050);
 //! ```
+//!
+//! # Streaming / progressive analysis
+//!
+//! For low-latency use cases like DJ software, [`ProgressiveKeyFinder`] lets you
+//! feed audio in chunks as it decodes and read an evolving key estimate, instead
+//! of buffering the whole track before calling [`KeyFinder::key_of_audio`].
+//!
+//! ```
+//! use libkeyfinder_sys::{AudioData, ProgressiveKeyFinder};
+//!
+//! let mut pkf = ProgressiveKeyFinder::new();
+//!
+//! let mut chunk = AudioData::new();
+//! chunk.set_frame_rate(44100);
+//! chunk.set_channels(1);
+//! chunk.push_samples(&[0.0; 4096]);
+//!
+//! let _estimate_so_far = pkf.feed(&mut chunk);
+//! let final_key = pkf.finalize();
+//! ```
+//!
+//! # Decoding audio files directly
+//!
+//! With the `symphonia` feature enabled, you don't need to hand-roll PCM
+//! decoding at all - [`AudioData::from_path`] probes and decodes the file for you:
+//!
+//! ```ignore
+//! use libkeyfinder_sys::AudioData;
+//!
+//! let audio = AudioData::from_path("track.flac")?;
+//! # Ok::<(), libkeyfinder_sys::DecodeError>(())
+//! ```
+
+#[cfg(feature = "symphonia")]
+mod decode;
+#[cfg(feature = "symphonia")]
+pub use decode::DecodeError;
+
+mod notation;
+pub use notation::ParseKeyError;
 
 #[doc(hidden)]
 #[cxx::bridge]
@@ -81,15 +124,30 @@ pub mod ffi {
         type KeyFinderWrapper;
         #[namespace = "keyfinder_bridge"]
         type AudioDataWrapper;
+        #[namespace = "keyfinder_bridge"]
+        type WorkspaceWrapper;
 
         #[namespace = "keyfinder_bridge"]
         fn new_keyfinder() -> UniquePtr<KeyFinderWrapper>;
         #[namespace = "keyfinder_bridge"]
         fn new_audiodata() -> UniquePtr<AudioDataWrapper>;
+        #[namespace = "keyfinder_bridge"]
+        fn new_workspace() -> UniquePtr<WorkspaceWrapper>;
 
         #[namespace = "keyfinder_bridge"]
         fn key_of_audio(kf: Pin<&mut KeyFinderWrapper>, audio: &AudioDataWrapper) -> u32;
 
+        #[namespace = "keyfinder_bridge"]
+        fn progressive_chromagram(
+            kf: Pin<&mut KeyFinderWrapper>,
+            audio: Pin<&mut AudioDataWrapper>,
+            workspace: Pin<&mut WorkspaceWrapper>,
+        );
+        #[namespace = "keyfinder_bridge"]
+        fn final_chromagram(kf: Pin<&mut KeyFinderWrapper>, workspace: Pin<&mut WorkspaceWrapper>);
+        #[namespace = "keyfinder_bridge"]
+        fn key_of_chromagram(kf: &KeyFinderWrapper, workspace: &WorkspaceWrapper) -> u32;
+
         #[namespace = "keyfinder_bridge"]
         fn set_frame_rate(audio: Pin<&mut AudioDataWrapper>, frame_rate: u32);
         #[namespace = "keyfinder_bridge"]
@@ -103,17 +161,21 @@ pub mod ffi {
         #[namespace = "keyfinder_bridge"]
         fn get_frame_count(audio: &AudioDataWrapper) -> u32;
         #[namespace = "keyfinder_bridge"]
-        fn add_to_sample_count(audio: Pin<&mut AudioDataWrapper>, samples: u32);
+        fn set_samples_from_slice(audio: Pin<&mut AudioDataWrapper>, samples: &[f32]);
         #[namespace = "keyfinder_bridge"]
-        fn reset_iterators(audio: Pin<&mut AudioDataWrapper>);
+        fn reduce_to_mono(audio: Pin<&mut AudioDataWrapper>);
         #[namespace = "keyfinder_bridge"]
-        fn advance_write_iterator(audio: Pin<&mut AudioDataWrapper>, by: u32);
+        fn downsample(audio: Pin<&mut AudioDataWrapper>, factor: u32);
+
         #[namespace = "keyfinder_bridge"]
-        fn set_sample_at_write_iterator(audio: Pin<&mut AudioDataWrapper>, sample: f32);
+        fn get_sample(audio: &AudioDataWrapper, index: u32) -> Result<f32>;
         #[namespace = "keyfinder_bridge"]
-        fn reduce_to_mono(audio: Pin<&mut AudioDataWrapper>);
+        fn set_sample(audio: Pin<&mut AudioDataWrapper>, index: u32, value: f32) -> Result<()>;
         #[namespace = "keyfinder_bridge"]
-        fn downsample(audio: Pin<&mut AudioDataWrapper>, factor: u32);
+        fn get_sample_by_frame(audio: &AudioDataWrapper, frame: u32, channel: u32) -> Result<f32>;
+
+        #[namespace = "keyfinder_bridge"]
+        fn low_pass_filter(audio: Pin<&mut AudioDataWrapper>, downsample_factor: u32);
     }
 }
 
@@ -144,6 +206,22 @@ pub mod ffi {
 ///     _ => println!("Key: {:?}", key),
 /// }
 /// ```
+///
+/// # Harmonic mixing notation
+///
+/// ```
+/// use libkeyfinder_sys::KeyFinderKey;
+///
+/// let key = KeyFinderKey::AMinor;
+/// assert_eq!(key.camelot(), Some("8A"));
+/// assert_eq!(key.open_key(), Some("1m"));
+/// assert_eq!(key.name(), Some("A minor"));
+/// assert_eq!(key.tonic(), Some("A"));
+/// assert_eq!(key.is_minor(), Some(true));
+///
+/// assert_eq!("8A".parse(), Ok(KeyFinderKey::AMinor));
+/// assert_eq!("F# minor".parse(), Ok(KeyFinderKey::GFlatMinor));
+/// ```
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum KeyFinderKey {
@@ -236,6 +314,109 @@ impl Default for KeyFinder {
     }
 }
 
+/// Incremental key detection over audio fed in chunks, for low-latency or
+/// streaming use cases (e.g. estimating the key while a track is still decoding).
+///
+/// Unlike [`KeyFinder::key_of_audio`], which needs the whole track buffered up
+/// front, `ProgressiveKeyFinder` keeps a persistent [`ffi::WorkspaceWrapper`]
+/// that accumulates a chromagram across calls to [`feed`](Self::feed). The
+/// workspace must not be reset between chunks, so create one
+/// `ProgressiveKeyFinder` per track and feed it chunks in order.
+///
+/// # Example
+///
+/// ```
+/// use libkeyfinder_sys::{AudioData, ProgressiveKeyFinder};
+///
+/// let mut pkf = ProgressiveKeyFinder::new();
+///
+/// for _ in 0..3 {
+///     let mut chunk = AudioData::new();
+///     chunk.set_frame_rate(44100);
+///     chunk.set_channels(1);
+///     chunk.push_samples(&[0.0; 4096]);
+///
+///     let _estimate_so_far = pkf.feed(&mut chunk);
+/// }
+///
+/// let final_key = pkf.finalize();
+/// println!("Detected key: {:?}", final_key);
+/// ```
+pub struct ProgressiveKeyFinder {
+    inner: cxx::UniquePtr<ffi::KeyFinderWrapper>,
+    workspace: cxx::UniquePtr<ffi::WorkspaceWrapper>,
+}
+
+impl ProgressiveKeyFinder {
+    /// Create a new progressive key finder with a fresh, empty workspace.
+    pub fn new() -> Self {
+        Self {
+            inner: ffi::new_keyfinder(),
+            workspace: ffi::new_workspace(),
+        }
+    }
+
+    /// Feed the next chunk of audio and return the current best key estimate.
+    ///
+    /// `audio` does not need to be the whole track - short chunks are fine.
+    /// The workspace carries state across calls, so chunks for the same track
+    /// must be fed through the same `ProgressiveKeyFinder` in order.
+    /// `progressiveChromagram` analyses a copy of `audio`, so the chunk passed
+    /// in is left unchanged.
+    pub fn feed(&mut self, audio: &mut AudioData) -> KeyFinderKey {
+        ffi::progressive_chromagram(
+            self.inner.pin_mut(),
+            audio.inner.pin_mut(),
+            self.workspace.pin_mut(),
+        );
+        let value = ffi::key_of_chromagram(&self.inner, &self.workspace);
+        KeyFinderKey::from_u32(value)
+    }
+
+    /// Finalize the chromagram and return the definitive key for the track.
+    ///
+    /// Call this once after the last [`feed`](Self::feed) call; it applies any
+    /// end-of-track corrections before reading back the key.
+    pub fn finalize(&mut self) -> KeyFinderKey {
+        ffi::final_chromagram(self.inner.pin_mut(), self.workspace.pin_mut());
+        let value = ffi::key_of_chromagram(&self.inner, &self.workspace);
+        KeyFinderKey::from_u32(value)
+    }
+}
+
+impl Default for ProgressiveKeyFinder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Errors returned by the fallible `AudioData` sample accessors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioError {
+    /// The requested sample or frame index is beyond the data currently held.
+    OutOfBounds,
+    /// The provided sample value is not finite (`NaN` or `+/-infinity`).
+    InvalidSample,
+    /// The operation requires monophonic audio but `AudioData` has more than
+    /// one channel.
+    NotMono,
+    /// A downsample factor of 0 was passed where a divisor is required.
+    ZeroDownsampleFactor,
+}
+
+impl std::fmt::Display for AudioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AudioError::OutOfBounds => write!(f, "sample or frame index out of bounds"),
+            AudioError::InvalidSample => write!(f, "sample value must be finite"),
+            AudioError::NotMono => write!(f, "audio must be reduced to mono first"),
+            AudioError::ZeroDownsampleFactor => write!(f, "downsample factor must be non-zero"),
+        }
+    }
+}
+
+impl std::error::Error for AudioError {}
+
 /// Container for decoded PCM audio samples.
 ///
 /// This wraps the C++ KeyFinder::AudioData class and manages the audio data
@@ -319,6 +500,67 @@ impl AudioData {
     pub fn downsample(&mut self, factor: u32) {
         ffi::downsample(self.inner.pin_mut(), factor);
     }
+
+    /// Apply libkeyfinder's recommended low-pass filter in place, as an
+    /// anti-aliasing step before calling [`AudioData::downsample`] with the
+    /// same `downsample_factor`.
+    ///
+    /// The filter's corner frequency is set relative to the Nyquist rate of
+    /// the signal *after* downsampling by `downsample_factor`, so it must
+    /// match the factor passed to the following `downsample` call — passing
+    /// a different factor there will under- or over-filter and can alias.
+    ///
+    /// Requires monophonic audio; call [`AudioData::reduce_to_mono`] first.
+    /// Returns [`AudioError::NotMono`] otherwise. Returns
+    /// [`AudioError::ZeroDownsampleFactor`] if `downsample_factor` is 0, since
+    /// that would divide by zero when computing the corner frequency.
+    pub fn low_pass_filter(&mut self, downsample_factor: u32) -> Result<(), AudioError> {
+        if self.channels() != 1 {
+            return Err(AudioError::NotMono);
+        }
+        if downsample_factor == 0 {
+            return Err(AudioError::ZeroDownsampleFactor);
+        }
+        ffi::low_pass_filter(self.inner.pin_mut(), downsample_factor);
+        Ok(())
+    }
+
+    /// Append samples in bulk, crossing the FFI boundary once for the whole slice.
+    ///
+    /// This is the canonical way to load audio: it hands the slice to C++ as a
+    /// single `rust::Slice`, which grows the sample count once and fills it with
+    /// a tight loop on the C++ side, rather than paying one FFI call per sample.
+    /// Samples should be in the range [-1.0, 1.0].
+    pub fn push_samples(&mut self, samples: &[f32]) {
+        ffi::set_samples_from_slice(self.inner.pin_mut(), samples);
+    }
+
+    /// Read back a single sample by its flat index.
+    ///
+    /// Returns [`AudioError::OutOfBounds`] if `index >= sample_count()`.
+    pub fn sample(&self, index: u32) -> Result<f32, AudioError> {
+        ffi::get_sample(&self.inner, index).map_err(|_| AudioError::OutOfBounds)
+    }
+
+    /// Overwrite a single sample by its flat index.
+    ///
+    /// Returns [`AudioError::InvalidSample`] if `value` is `NaN` or infinite
+    /// (checked before crossing into C++), or [`AudioError::OutOfBounds`] if
+    /// `index >= sample_count()`.
+    pub fn set_sample(&mut self, index: u32, value: f32) -> Result<(), AudioError> {
+        if !value.is_finite() {
+            return Err(AudioError::InvalidSample);
+        }
+        ffi::set_sample(self.inner.pin_mut(), index, value).map_err(|_| AudioError::OutOfBounds)
+    }
+
+    /// Read back a sample addressed by frame and channel rather than flat index.
+    ///
+    /// Returns [`AudioError::OutOfBounds`] if `frame >= frame_count()` or
+    /// `channel >= channels()`.
+    pub fn sample_by_frame(&self, frame: u32, channel: u32) -> Result<f32, AudioError> {
+        ffi::get_sample_by_frame(&self.inner, frame, channel).map_err(|_| AudioError::OutOfBounds)
+    }
 }
 
 impl Default for AudioData {
@@ -330,23 +572,12 @@ impl Default for AudioData {
 impl Extend<f32> for AudioData {
     /// Extend the audio data with samples from an iterator.
     ///
+    /// The iterator is collected into a `Vec` and handed to [`AudioData::push_samples`]
+    /// in one bulk call, rather than crossing the FFI boundary per sample.
     /// Samples should be in the range [-1.0, 1.0].
     fn extend<T: IntoIterator<Item = f32>>(&mut self, iter: T) {
-        // Collect into a Vec for efficient batch processing
         let samples: Vec<f32> = iter.into_iter().collect();
-        if samples.is_empty() {
-            return;
-        }
-
-        let old_count = self.sample_count();
-        ffi::add_to_sample_count(self.inner.pin_mut(), samples.len() as u32);
-        ffi::reset_iterators(self.inner.pin_mut());
-        ffi::advance_write_iterator(self.inner.pin_mut(), old_count);
-
-        for &sample in &samples {
-            ffi::set_sample_at_write_iterator(self.inner.pin_mut(), sample);
-            ffi::advance_write_iterator(self.inner.pin_mut(), 1);
-        }
+        self.push_samples(&samples);
     }
 }
 
@@ -381,6 +612,19 @@ mod tests {
         assert_eq!(audio.channels(), 2);
     }
 
+    #[test]
+    fn test_audio_data_push_samples() {
+        let mut audio = AudioData::new();
+        audio.set_frame_rate(44100);
+        audio.set_channels(2);
+
+        audio.push_samples(&[0.1, -0.2, 0.3, -0.4]);
+        audio.push_samples(&[0.5, -0.6]);
+
+        assert_eq!(audio.sample_count(), 6);
+        assert_eq!(audio.frame_count(), 3);
+    }
+
     #[test]
     fn test_audio_data_extend() {
         let mut audio = AudioData::new();
@@ -434,6 +678,39 @@ mod tests {
         assert_eq!(audio.frame_rate(), 22050);
     }
 
+    #[test]
+    fn test_audio_data_low_pass_filter() {
+        let mut audio = AudioData::new();
+        audio.set_frame_rate(44100);
+        audio.set_channels(1);
+        audio.push_samples(&[0.0; 4096]);
+
+        assert!(audio.low_pass_filter(2).is_ok());
+    }
+
+    #[test]
+    fn test_audio_data_low_pass_filter_rejects_multichannel() {
+        let mut audio = AudioData::new();
+        audio.set_frame_rate(44100);
+        audio.set_channels(2);
+        audio.push_samples(&[0.0; 4096]);
+
+        assert_eq!(audio.low_pass_filter(2), Err(AudioError::NotMono));
+    }
+
+    #[test]
+    fn test_audio_data_low_pass_filter_rejects_zero_downsample_factor() {
+        let mut audio = AudioData::new();
+        audio.set_frame_rate(44100);
+        audio.set_channels(1);
+        audio.push_samples(&[0.0; 4096]);
+
+        assert_eq!(
+            audio.low_pass_filter(0),
+            Err(AudioError::ZeroDownsampleFactor)
+        );
+    }
+
     #[test]
     fn test_keyfinder_new() {
         let _kf = KeyFinder::new();
@@ -487,4 +764,154 @@ mod tests {
         let key = kf.key_of_audio(&audio);
         assert_eq!(key, KeyFinderKey::Silence);
     }
+
+    #[test]
+    fn test_progressive_keyfinder_feed_and_finalize() {
+        let mut pkf = ProgressiveKeyFinder::new();
+
+        for _ in 0..3 {
+            let mut chunk = AudioData::new();
+            chunk.set_frame_rate(44100);
+            chunk.set_channels(1);
+            chunk.push_samples(&[0.0; 4096]);
+
+            let _ = pkf.feed(&mut chunk);
+        }
+
+        assert_eq!(pkf.finalize(), KeyFinderKey::Silence);
+    }
+
+    #[test]
+    fn test_progressive_keyfinder_default() {
+        let _pkf = ProgressiveKeyFinder::default();
+    }
+
+    #[test]
+    fn test_audio_data_sample_accessors() {
+        let mut audio = AudioData::new();
+        audio.set_frame_rate(44100);
+        audio.set_channels(2);
+        audio.push_samples(&[0.1, -0.2, 0.3, -0.4]);
+
+        assert_eq!(audio.sample(0), Ok(0.1));
+        assert_eq!(audio.sample_by_frame(1, 1), Ok(-0.4));
+
+        audio.set_sample(0, 0.5).unwrap();
+        assert_eq!(audio.sample(0), Ok(0.5));
+    }
+
+    #[test]
+    fn test_audio_data_sample_out_of_bounds() {
+        let mut audio = AudioData::new();
+        audio.set_frame_rate(44100);
+        audio.set_channels(1);
+        audio.push_samples(&[0.1, 0.2]);
+
+        assert_eq!(audio.sample(2), Err(AudioError::OutOfBounds));
+        assert_eq!(audio.sample_by_frame(2, 0), Err(AudioError::OutOfBounds));
+        assert_eq!(audio.set_sample(2, 0.0), Err(AudioError::OutOfBounds));
+    }
+
+    #[test]
+    fn test_audio_data_set_sample_rejects_non_finite() {
+        let mut audio = AudioData::new();
+        audio.set_frame_rate(44100);
+        audio.set_channels(1);
+        audio.push_samples(&[0.1, 0.2]);
+
+        assert_eq!(
+            audio.set_sample(0, f32::NAN),
+            Err(AudioError::InvalidSample)
+        );
+        assert_eq!(
+            audio.set_sample(0, f32::INFINITY),
+            Err(AudioError::InvalidSample)
+        );
+    }
+
+    #[cfg(feature = "symphonia")]
+    #[test]
+    fn test_decode_error_display() {
+        let err = DecodeError::NoAudioTrack;
+        assert_eq!(err.to_string(), "stream has no decodable audio track");
+    }
+
+    /// Builds a minimal mono 16-bit PCM WAV file in memory: a RIFF/WAVE header
+    /// followed by a `fmt ` and `data` chunk wrapping `samples`.
+    #[cfg(feature = "symphonia")]
+    fn write_test_wav(frame_rate: u32, samples: &[i16]) -> Vec<u8> {
+        let data_bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        let byte_rate = frame_rate * 2;
+        let data_len = data_bytes.len() as u32;
+        let riff_len = 36 + data_len;
+
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&riff_len.to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+        wav.extend_from_slice(&frame_rate.to_le_bytes());
+        wav.extend_from_slice(&byte_rate.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+        wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        wav.extend_from_slice(&data_bytes);
+        wav
+    }
+
+    #[cfg(feature = "symphonia")]
+    #[test]
+    fn test_audio_data_from_reader_decodes_wav() {
+        let samples: Vec<i16> = (0..1000).map(|i| ((i % 200) - 100) as i16 * 300).collect();
+        let wav = write_test_wav(8000, &samples);
+
+        let audio =
+            AudioData::from_reader(std::io::Cursor::new(wav)).expect("failed to decode test wav");
+
+        assert_eq!(audio.frame_rate(), 8000);
+        assert_eq!(audio.channels(), 1);
+        assert_eq!(audio.frame_count(), samples.len() as u32);
+    }
+
+    #[test]
+    fn test_key_camelot_and_open_key() {
+        assert_eq!(KeyFinderKey::AMinor.camelot(), Some("8A"));
+        assert_eq!(KeyFinderKey::CMajor.camelot(), Some("8B"));
+        assert_eq!(KeyFinderKey::AMinor.open_key(), Some("1m"));
+        assert_eq!(KeyFinderKey::CMajor.open_key(), Some("1d"));
+        assert_eq!(KeyFinderKey::GMajor.open_key(), Some("8d"));
+        assert_eq!(KeyFinderKey::Silence.camelot(), None);
+        assert_eq!(KeyFinderKey::Silence.open_key(), None);
+    }
+
+    #[test]
+    fn test_key_name_and_decomposition() {
+        assert_eq!(KeyFinderKey::AMinor.name(), Some("A minor"));
+        assert_eq!(KeyFinderKey::AMinor.tonic(), Some("A"));
+        assert_eq!(KeyFinderKey::AMinor.is_minor(), Some(true));
+        assert_eq!(KeyFinderKey::CMajor.is_minor(), Some(false));
+        assert_eq!(KeyFinderKey::Silence.name(), None);
+    }
+
+    #[test]
+    fn test_key_from_str_camelot() {
+        assert_eq!("8A".parse(), Ok(KeyFinderKey::AMinor));
+        assert_eq!("8a".parse(), Ok(KeyFinderKey::AMinor));
+        assert_eq!("8B".parse(), Ok(KeyFinderKey::CMajor));
+        assert!("13A".parse::<KeyFinderKey>().is_err());
+        assert!("0B".parse::<KeyFinderKey>().is_err());
+    }
+
+    #[test]
+    fn test_key_from_str_name() {
+        assert_eq!("A minor".parse(), Ok(KeyFinderKey::AMinor));
+        assert_eq!("F# minor".parse(), Ok(KeyFinderKey::GFlatMinor));
+        assert_eq!("Gb minor".parse(), Ok(KeyFinderKey::GFlatMinor));
+        assert_eq!("Bb major".parse(), Ok(KeyFinderKey::BFlatMajor));
+        assert!("not a key".parse::<KeyFinderKey>().is_err());
+    }
 }
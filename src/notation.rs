@@ -0,0 +1,334 @@
+//! Camelot wheel, Open Key, and human-readable notation for [`KeyFinderKey`].
+
+use std::str::FromStr;
+
+use crate::KeyFinderKey;
+
+struct KeyInfo {
+    camelot: &'static str,
+    open_key: &'static str,
+    tonic: &'static str,
+    name: &'static str,
+    is_minor: bool,
+}
+
+// Indexed by the `KeyFinderKey` discriminant (0..=23); `Silence` (24) has no entry.
+//
+// Open Key numbers are assigned by chromatic distance from C (C=1, Db=2, D=3, ...,
+// B=12), unlike Camelot's circle-of-fifths numbering; a minor key shares its
+// relative major's number (e.g. C major and A minor are both "1").
+const KEY_TABLE: [KeyInfo; 24] = [
+    KeyInfo {
+        camelot: "11B",
+        open_key: "10d",
+        tonic: "A",
+        name: "A major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "8A",
+        open_key: "1m",
+        tonic: "A",
+        name: "A minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "6B",
+        open_key: "11d",
+        tonic: "Bb",
+        name: "Bb major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "3A",
+        open_key: "2m",
+        tonic: "Bb",
+        name: "Bb minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "1B",
+        open_key: "12d",
+        tonic: "B",
+        name: "B major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "10A",
+        open_key: "3m",
+        tonic: "B",
+        name: "B minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "8B",
+        open_key: "1d",
+        tonic: "C",
+        name: "C major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "5A",
+        open_key: "4m",
+        tonic: "C",
+        name: "C minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "3B",
+        open_key: "2d",
+        tonic: "Db",
+        name: "Db major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "12A",
+        open_key: "5m",
+        tonic: "Db",
+        name: "Db minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "10B",
+        open_key: "3d",
+        tonic: "D",
+        name: "D major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "7A",
+        open_key: "6m",
+        tonic: "D",
+        name: "D minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "5B",
+        open_key: "4d",
+        tonic: "Eb",
+        name: "Eb major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "2A",
+        open_key: "7m",
+        tonic: "Eb",
+        name: "Eb minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "12B",
+        open_key: "5d",
+        tonic: "E",
+        name: "E major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "9A",
+        open_key: "8m",
+        tonic: "E",
+        name: "E minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "7B",
+        open_key: "6d",
+        tonic: "F",
+        name: "F major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "4A",
+        open_key: "9m",
+        tonic: "F",
+        name: "F minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "2B",
+        open_key: "7d",
+        tonic: "Gb",
+        name: "Gb major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "11A",
+        open_key: "10m",
+        tonic: "Gb",
+        name: "Gb minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "9B",
+        open_key: "8d",
+        tonic: "G",
+        name: "G major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "6A",
+        open_key: "11m",
+        tonic: "G",
+        name: "G minor",
+        is_minor: true,
+    },
+    KeyInfo {
+        camelot: "4B",
+        open_key: "9d",
+        tonic: "Ab",
+        name: "Ab major",
+        is_minor: false,
+    },
+    KeyInfo {
+        camelot: "1A",
+        open_key: "12m",
+        tonic: "Ab",
+        name: "Ab minor",
+        is_minor: true,
+    },
+];
+
+impl KeyFinderKey {
+    fn info(&self) -> Option<&'static KeyInfo> {
+        if *self == KeyFinderKey::Silence {
+            None
+        } else {
+            Some(&KEY_TABLE[*self as usize])
+        }
+    }
+
+    /// The Camelot wheel code for this key (e.g. `"8A"` for A minor, `"8B"` for C major).
+    ///
+    /// Returns `None` for [`KeyFinderKey::Silence`].
+    pub fn camelot(&self) -> Option<&'static str> {
+        self.info().map(|info| info.camelot)
+    }
+
+    /// The Open Key (Traktor) code for this key (e.g. `"1m"` for A minor, `"1d"` for C major).
+    ///
+    /// Returns `None` for [`KeyFinderKey::Silence`].
+    pub fn open_key(&self) -> Option<&'static str> {
+        self.info().map(|info| info.open_key)
+    }
+
+    /// A human-readable name for this key, e.g. `"A minor"` or `"Bb major"`.
+    ///
+    /// Returns `None` for [`KeyFinderKey::Silence`].
+    pub fn name(&self) -> Option<&'static str> {
+        self.info().map(|info| info.name)
+    }
+
+    /// The tonic note name, e.g. `"A"` or `"Bb"`.
+    ///
+    /// Returns `None` for [`KeyFinderKey::Silence`].
+    pub fn tonic(&self) -> Option<&'static str> {
+        self.info().map(|info| info.tonic)
+    }
+
+    /// Whether this key is minor (as opposed to major).
+    ///
+    /// Returns `None` for [`KeyFinderKey::Silence`].
+    pub fn is_minor(&self) -> Option<bool> {
+        self.info().map(|info| info.is_minor)
+    }
+}
+
+/// Error returned when a string doesn't match any Camelot code or key name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKeyError(String);
+
+impl std::fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} is not a recognized Camelot code or key name",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseKeyError {}
+
+impl FromStr for KeyFinderKey {
+    type Err = ParseKeyError;
+
+    /// Parse either a Camelot code (`"8A"`) or a key name (`"A minor"`, `"F# minor"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        parse_camelot(trimmed)
+            .or_else(|| parse_name(trimmed))
+            .ok_or_else(|| ParseKeyError(s.to_string()))
+    }
+}
+
+fn all_keys() -> impl Iterator<Item = KeyFinderKey> {
+    (0..24).map(KeyFinderKey::from_u32)
+}
+
+fn parse_camelot(s: &str) -> Option<KeyFinderKey> {
+    let mut chars = s.chars();
+    let letter = chars.next_back()?.to_ascii_uppercase();
+    if letter != 'A' && letter != 'B' {
+        return None;
+    }
+
+    let number: u32 = chars.as_str().parse().ok()?;
+    if !(1..=12).contains(&number) {
+        return None;
+    }
+
+    let code = format!("{number}{letter}");
+    all_keys().find(|key| key.camelot() == Some(code.as_str()))
+}
+
+fn parse_name(s: &str) -> Option<KeyFinderKey> {
+    let mut parts = s.split_whitespace();
+    let tonic = normalize_tonic(parts.next()?)?;
+    let is_minor = match parts.next()?.to_ascii_lowercase().as_str() {
+        "major" => false,
+        "minor" => true,
+        _ => return None,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    all_keys().find(|key| key.tonic() == Some(tonic) && key.is_minor() == Some(is_minor))
+}
+
+/// Normalize a tonic spelling (`"F#"`, `"Gb"`, `"Gsharp"`, ...) to the canonical
+/// spelling used by [`KEY_TABLE`] (one of the 12 chromatic note names libkeyfinder
+/// represents, always spelled with flats for the black keys).
+fn normalize_tonic(s: &str) -> Option<&'static str> {
+    let mut chars = s.chars();
+    let letter = chars.next()?.to_ascii_uppercase();
+    if !('A'..='G').contains(&letter) {
+        return None;
+    }
+
+    let accidental = chars.as_str().to_ascii_lowercase();
+    let is_natural = accidental.is_empty();
+    let is_flat = accidental == "b" || accidental == "flat";
+    let is_sharp = accidental == "#" || accidental == "sharp";
+
+    match (letter, is_natural, is_flat, is_sharp) {
+        ('A', true, ..) => Some("A"),
+        ('A', _, true, _) => Some("Ab"),
+        ('A', _, _, true) => Some("Bb"),
+        ('B', true, ..) => Some("B"),
+        ('B', _, true, _) => Some("Bb"),
+        ('C', true, ..) => Some("C"),
+        ('C', _, _, true) => Some("Db"),
+        ('D', true, ..) => Some("D"),
+        ('D', _, true, _) => Some("Db"),
+        ('D', _, _, true) => Some("Eb"),
+        ('E', true, ..) => Some("E"),
+        ('E', _, true, _) => Some("Eb"),
+        ('F', true, ..) => Some("F"),
+        ('F', _, _, true) => Some("Gb"),
+        ('G', true, ..) => Some("G"),
+        ('G', _, true, _) => Some("Gb"),
+        ('G', _, _, true) => Some("Ab"),
+        _ => None,
+    }
+}